@@ -19,9 +19,72 @@ pub fn parse(tnid_str: &str) -> Result<String, JsError> {
 }
 
 /// Parse a UUID string with the given name and return a TNID string.
+///
+/// Accepts the hyphenated, simple, `urn:uuid:`, and braced forms.
 #[wasm_bindgen]
 pub fn parse_uuid(uuid_str: &str) -> Result<String, JsError> {
-    let tnid = DynamicTnid::parse_uuid_string(uuid_str)?;
+    let tnid = DynamicTnid::parse_uuid_string(&normalize_uuid_input(uuid_str))?;
+    Ok(tnid.to_string())
+}
+
+/// Normalise any of the supported UUID string forms to the hyphenated layout.
+/// The case of the hex digits is left as given.
+fn normalize_uuid_input(uuid_str: &str) -> String {
+    let trimmed = uuid_str.trim();
+    let trimmed = trimmed.strip_prefix("urn:uuid:").unwrap_or(trimmed);
+    let trimmed = trimmed
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .unwrap_or(trimmed);
+
+    // Re-insert hyphens for the "simple" (dash-free) form so the underlying
+    // parser always sees a canonical hyphenated layout.
+    let hex: String = trimmed.chars().filter(|c| *c != '-').collect();
+    if hex.len() == 32 {
+        format!(
+            "{}-{}-{}-{}-{}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32],
+        )
+    } else {
+        trimmed.to_string()
+    }
+}
+
+/// Create a new V0 (time-ordered) TNID, reading the current time and randomness
+/// from the host.
+///
+/// # Arguments
+/// * `name` - The TNID name (1-4 characters)
+#[wasm_bindgen]
+pub fn new_v0_now(name: &str) -> Result<String, JsError> {
+    let name = NameStr::new(name).map_err(|e| JsError::new(&e.to_string()))?;
+
+    let mut buf = [0u8; 8];
+    getrandom::getrandom(&mut buf).map_err(|e| JsError::new(&e.to_string()))?;
+    let random = u64::from_be_bytes(buf);
+
+    let timestamp_ms = js_sys::Date::now() as u64;
+    let tnid = DynamicTnid::new_v0_with_parts(name, timestamp_ms, random);
+    Ok(tnid.to_string())
+}
+
+/// Create a new V1 (high-entropy) TNID, reading randomness from the host.
+///
+/// # Arguments
+/// * `name` - The TNID name (1-4 characters)
+#[wasm_bindgen]
+pub fn new_v1_now(name: &str) -> Result<String, JsError> {
+    let name = NameStr::new(name).map_err(|e| JsError::new(&e.to_string()))?;
+
+    let mut buf = [0u8; 16];
+    getrandom::getrandom(&mut buf).map_err(|e| JsError::new(&e.to_string()))?;
+    let random = u128::from_be_bytes(buf);
+
+    let tnid = DynamicTnid::new_v1_with_random(name, random);
     Ok(tnid.to_string())
 }
 
@@ -70,6 +133,61 @@ pub fn new_v1(name: &str, random_hex: &str) -> Result<String, JsError> {
     Ok(tnid.to_string())
 }
 
+/// Create a deterministic, name-based TNID by MD5-hashing a namespace and data.
+///
+/// # Arguments
+/// * `name` - The TNID name (1-4 characters)
+/// * `namespace_tnid_str` - A TNID string used as the hashing namespace
+/// * `data` - Arbitrary name/data string (e.g. a URL, email, or file path)
+#[wasm_bindgen]
+pub fn new_v3_md5(name: &str, namespace_tnid_str: &str, data: &str) -> Result<String, JsError> {
+    let name = NameStr::new(name).map_err(|e| JsError::new(&e.to_string()))?;
+    let namespace = DynamicTnid::parse_tnid_string(namespace_tnid_str)?;
+
+    let mut buf = Vec::with_capacity(16 + data.len());
+    buf.extend_from_slice(&namespace.as_u128().to_be_bytes());
+    buf.extend_from_slice(data.as_bytes());
+
+    let hash = md5::compute(&buf).0;
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&hash[..16]);
+
+    let tnid = DynamicTnid::from_u128(name, u128::from_be_bytes(set_name_based_nibbles(bytes, 3)));
+    Ok(tnid.to_string())
+}
+
+/// Create a deterministic, name-based TNID by SHA-1-hashing a namespace and data.
+///
+/// # Arguments
+/// * `name` - The TNID name (1-4 characters)
+/// * `namespace_tnid_str` - A TNID string used as the hashing namespace
+/// * `data` - Arbitrary name/data string (e.g. a URL, email, or file path)
+#[wasm_bindgen]
+pub fn new_v5_sha1(name: &str, namespace_tnid_str: &str, data: &str) -> Result<String, JsError> {
+    use sha1::{Digest, Sha1};
+
+    let name = NameStr::new(name).map_err(|e| JsError::new(&e.to_string()))?;
+    let namespace = DynamicTnid::parse_tnid_string(namespace_tnid_str)?;
+
+    let mut hasher = Sha1::new();
+    hasher.update(namespace.as_u128().to_be_bytes());
+    hasher.update(data.as_bytes());
+    let hash = hasher.finalize();
+
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&hash[..16]);
+
+    let tnid = DynamicTnid::from_u128(name, u128::from_be_bytes(set_name_based_nibbles(bytes, 5)));
+    Ok(tnid.to_string())
+}
+
+/// Rewrite the version nibble and RFC 4122 variant bits for a name-based value.
+fn set_name_based_nibbles(mut bytes: [u8; 16], version: u8) -> [u8; 16] {
+    bytes[6] = (bytes[6] & 0x0f) | (version << 4);
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    bytes
+}
+
 /// Convert a TNID to its UUID string representation.
 ///
 /// Returns lowercase UUID format (e.g., "550e8400-e29b-41d4-a716-446655440000").
@@ -79,6 +197,56 @@ pub fn to_uuid_string(tnid_str: &str) -> Result<String, JsError> {
     Ok(tnid.to_uuid_string(Case::Lower))
 }
 
+/// Convert a TNID to a UUID string in the requested format and case.
+///
+/// # Arguments
+/// * `tnid_str` - A TNID string
+/// * `style` - One of `"hyphenated"`, `"simple"`, `"urn"`, or `"braced"`
+/// * `uppercase` - Whether to emit uppercase hex digits
+#[wasm_bindgen]
+pub fn format_uuid(tnid_str: &str, style: &str, uppercase: bool) -> Result<String, JsError> {
+    let tnid = DynamicTnid::parse_tnid_string(tnid_str)?;
+    let case = if uppercase { Case::Upper } else { Case::Lower };
+    let hyphenated = tnid.to_uuid_string(case);
+
+    let formatted = match style {
+        "hyphenated" => hyphenated,
+        "simple" => hyphenated.replace('-', ""),
+        "urn" => format!("urn:uuid:{hyphenated}"),
+        "braced" => format!("{{{hyphenated}}}"),
+        other => {
+            return Err(JsError::new(&format!(
+                "unknown UUID style \"{other}\"; expected hyphenated, simple, urn, or braced"
+            )))
+        }
+    };
+    Ok(formatted)
+}
+
+/// Return the 16-octet big-endian form of a TNID's underlying 128-bit value.
+///
+/// Useful for binary protocols, IndexedDB keys, or length-prefixed wire formats
+/// that would otherwise require a hex round-trip.
+#[wasm_bindgen]
+pub fn to_bytes(tnid_str: &str) -> Result<Vec<u8>, JsError> {
+    let tnid = DynamicTnid::parse_tnid_string(tnid_str)?;
+    Ok(tnid.as_u128().to_be_bytes().to_vec())
+}
+
+/// Reconstruct a TNID string from a name plus exactly 16 big-endian bytes.
+///
+/// Throws if `bytes` is not exactly 16 octets long, or if the name is invalid.
+#[wasm_bindgen]
+pub fn from_bytes(name: &str, bytes: &[u8]) -> Result<String, JsError> {
+    let name = NameStr::new(name).map_err(|e| JsError::new(&e.to_string()))?;
+    let bytes: [u8; 16] = bytes
+        .try_into()
+        .map_err(|_| JsError::new("bytes must be exactly 16 octets"))?;
+
+    let tnid = DynamicTnid::from_u128(name, u128::from_be_bytes(bytes));
+    Ok(tnid.to_string())
+}
+
 /// Get the variant of a TNID ("v0", "v1", "v2", or "v3").
 #[wasm_bindgen]
 pub fn get_variant(tnid_str: &str) -> Result<String, JsError> {
@@ -99,6 +267,22 @@ pub fn get_name(tnid_str: &str) -> Result<String, JsError> {
     Ok(tnid.name())
 }
 
+/// Recover the Unix millisecond timestamp embedded in a V0 (time-ordered) TNID.
+///
+/// Returns the timestamp as an `f64`, ready to feed straight into `new Date(ms)`.
+/// Throws if the TNID is not time-ordered (V1/V2/V3), since those variants carry
+/// no recoverable timestamp and would otherwise yield a meaningless number.
+#[wasm_bindgen]
+pub fn get_timestamp_ms(tnid_str: &str) -> Result<f64, JsError> {
+    let tnid = DynamicTnid::parse_tnid_string(tnid_str)?;
+    match tnid.variant() {
+        TnidVariant::V0 => Ok(tnid.timestamp_ms() as f64),
+        _ => Err(JsError::new(
+            "TNID is not time-ordered; only V0 TNIDs carry an embedded timestamp",
+        )),
+    }
+}
+
 /// Encrypt a V0 TNID to V1 format.
 ///
 /// # Arguments
@@ -142,3 +326,191 @@ pub fn decrypt_v1_to_v0(tnid_str: &str, key_hex: &str) -> Result<String, JsError
 pub fn is_valid_name(name: &str) -> bool {
     NameStr::new(name).is_ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CANONICAL: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+    // JsError carries no Debug impl, so unwrap the Ok branch by hand.
+    fn ok(result: Result<String, JsError>) -> String {
+        match result {
+            Ok(s) => s,
+            Err(_) => panic!("expected Ok, got Err"),
+        }
+    }
+
+    #[test]
+    fn get_timestamp_ms_recovers_v0_time() {
+        let tnid = ok(new_v0("test", 1_700_000_000_000.0, "0123456789abcdef"));
+        match get_timestamp_ms(&tnid) {
+            Ok(ms) => assert_eq!(ms, 1_700_000_000_000.0),
+            Err(_) => panic!("expected a timestamp for a V0 TNID"),
+        }
+    }
+
+    #[test]
+    fn get_timestamp_ms_rejects_non_v0() {
+        let tnid = ok(new_v1("test", "0123456789abcdef0123456789abcdef"));
+        assert!(get_timestamp_ms(&tnid).is_err());
+    }
+
+    #[test]
+    fn name_based_nibbles_set_version_and_variant() {
+        // All-ones input proves the masks clear the right bits.
+        let v3 = set_name_based_nibbles([0xff; 16], 3);
+        assert_eq!(v3[6] >> 4, 3, "version nibble");
+        assert_eq!(v3[8] & 0xc0, 0x80, "RFC 4122 variant bits");
+
+        let v5 = set_name_based_nibbles([0xff; 16], 5);
+        assert_eq!(v5[6] >> 4, 5, "version nibble");
+        assert_eq!(v5[8] & 0xc0, 0x80, "RFC 4122 variant bits");
+    }
+
+    #[test]
+    fn name_based_nibbles_preserve_other_bits() {
+        let input = [0u8; 16];
+        let out = set_name_based_nibbles(input, 3);
+        assert_eq!(out[6], 0x30);
+        assert_eq!(out[8], 0x80);
+        // Every other octet is untouched.
+        for (i, b) in out.iter().enumerate() {
+            if i != 6 && i != 8 {
+                assert_eq!(*b, 0);
+            }
+        }
+    }
+
+    #[test]
+    fn name_based_nibbles_are_deterministic() {
+        let input = [0x12; 16];
+        assert_eq!(
+            set_name_based_nibbles(input, 5),
+            set_name_based_nibbles(input, 5)
+        );
+    }
+
+    #[test]
+    fn name_based_generation_is_reproducible() {
+        let ns = ok(new_v1("ns", "0123456789abcdef0123456789abcdef"));
+
+        // Same (namespace, data) always yields byte-identical output.
+        assert_eq!(
+            ok(new_v3_md5("test", &ns, "https://example.com")),
+            ok(new_v3_md5("test", &ns, "https://example.com"))
+        );
+        assert_eq!(
+            ok(new_v5_sha1("test", &ns, "https://example.com")),
+            ok(new_v5_sha1("test", &ns, "https://example.com"))
+        );
+    }
+
+    #[test]
+    fn name_based_generation_distinguishes_inputs_and_algorithms() {
+        let ns = ok(new_v1("ns", "0123456789abcdef0123456789abcdef"));
+
+        // Different data produces a different TNID.
+        assert_ne!(
+            ok(new_v3_md5("test", &ns, "a")),
+            ok(new_v3_md5("test", &ns, "b"))
+        );
+        // MD5 and SHA-1 paths diverge for the same inputs.
+        assert_ne!(
+            ok(new_v3_md5("test", &ns, "a")),
+            ok(new_v5_sha1("test", &ns, "a"))
+        );
+    }
+
+    #[test]
+    fn normalize_accepts_all_four_styles() {
+        assert_eq!(normalize_uuid_input(CANONICAL), CANONICAL);
+        assert_eq!(
+            normalize_uuid_input("550e8400e29b41d4a716446655440000"),
+            CANONICAL
+        );
+        assert_eq!(
+            normalize_uuid_input("urn:uuid:550e8400-e29b-41d4-a716-446655440000"),
+            CANONICAL
+        );
+        assert_eq!(
+            normalize_uuid_input("{550e8400-e29b-41d4-a716-446655440000}"),
+            CANONICAL
+        );
+    }
+
+    #[test]
+    fn normalize_preserves_mixed_case_and_trims() {
+        assert_eq!(
+            normalize_uuid_input("  550E8400E29B41D4A716446655440000  "),
+            "550E8400-E29B-41D4-A716-446655440000"
+        );
+    }
+
+    #[test]
+    fn format_uuid_renders_each_style() {
+        let tnid = ok(new_v1("test", "0123456789abcdef0123456789abcdef"));
+        let hyphenated = ok(format_uuid(&tnid, "hyphenated", false));
+
+        assert_eq!(
+            ok(format_uuid(&tnid, "simple", false)),
+            hyphenated.replace('-', "")
+        );
+        assert_eq!(
+            ok(format_uuid(&tnid, "urn", false)),
+            format!("urn:uuid:{hyphenated}")
+        );
+        assert_eq!(
+            ok(format_uuid(&tnid, "braced", false)),
+            format!("{{{hyphenated}}}")
+        );
+    }
+
+    #[test]
+    fn format_uuid_honors_uppercase_flag() {
+        let tnid = ok(new_v1("test", "0123456789abcdef0123456789abcdef"));
+        let lower = ok(format_uuid(&tnid, "hyphenated", false));
+        let upper = ok(format_uuid(&tnid, "hyphenated", true));
+        assert_eq!(upper, lower.to_uppercase());
+    }
+
+    #[test]
+    fn format_uuid_rejects_unknown_style() {
+        let tnid = ok(new_v1("test", "0123456789abcdef0123456789abcdef"));
+        assert!(format_uuid(&tnid, "bogus", false).is_err());
+    }
+
+    #[test]
+    fn parse_uuid_round_trips_every_style() {
+        let tnid = ok(new_v1("test", "0123456789abcdef0123456789abcdef"));
+        for style in ["hyphenated", "simple", "urn", "braced"] {
+            let formatted = ok(format_uuid(&tnid, style, false));
+            assert_eq!(ok(parse_uuid(&formatted)), tnid, "round-trip for {style}");
+        }
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let tnid = ok(new_v1("test", "0123456789abcdef0123456789abcdef"));
+        let bytes = match to_bytes(&tnid) {
+            Ok(b) => b,
+            Err(_) => panic!("expected bytes"),
+        };
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(ok(from_bytes("test", &bytes)), tnid);
+    }
+
+    #[test]
+    fn from_bytes_rejects_wrong_length() {
+        assert!(from_bytes("test", &[0u8; 15]).is_err());
+        assert!(from_bytes("test", &[0u8; 17]).is_err());
+    }
+
+    #[test]
+    fn normalize_leaves_malformed_input_for_the_parser() {
+        // Not 32 hex chars once dashes are stripped: pass through untouched so the
+        // underlying parser produces the error.
+        assert_eq!(normalize_uuid_input("not-a-uuid"), "not-a-uuid");
+        assert_eq!(normalize_uuid_input("550e8400"), "550e8400");
+    }
+}